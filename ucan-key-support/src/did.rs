@@ -0,0 +1,94 @@
+use anyhow::{anyhow, Result};
+
+use ucan::crypto::KeyMaterial;
+
+use crate::ed25519::{bytes_to_ed25519_key, ED25519_MAGIC_BYTES};
+use crate::k256::{bytes_to_k256_key, SECP256K1_MAGIC_BYTES};
+use crate::p256::{bytes_to_p256_key, P256_MAGIC_BYTES};
+use crate::rsa::{bytes_to_rsa_key, RSA_MAGIC_BYTES};
+
+/// Parse a `did:key:z...` string into a verify-only [`KeyMaterial`], auto-dispatching
+/// on the leading multicodec prefix to the matching key type. This saves callers from
+/// having to assemble their own codec table and register it with a [`ucan::crypto::did::DidParser`].
+pub fn parse_did_key(did: &str) -> Result<Box<dyn KeyMaterial>> {
+    let encoded = did
+        .strip_prefix("did:key:z")
+        .ok_or_else(|| anyhow!("Not a valid did:key: {}", did))?;
+
+    let bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|error| anyhow!("Could not base58btc-decode did:key payload: {}", error))?;
+
+    if bytes.len() < 2 {
+        return Err(anyhow!(
+            "did:key payload is too short to contain a multicodec prefix"
+        ));
+    }
+
+    let magic_bytes = [bytes[0], bytes[1]];
+    let key_bytes = bytes[2..].to_vec();
+
+    match magic_bytes {
+        ED25519_MAGIC_BYTES => bytes_to_ed25519_key(key_bytes),
+        P256_MAGIC_BYTES => bytes_to_p256_key(key_bytes),
+        SECP256K1_MAGIC_BYTES => bytes_to_k256_key(key_bytes),
+        RSA_MAGIC_BYTES => bytes_to_rsa_key(key_bytes),
+        _ => Err(anyhow!(
+            "Unsupported did:key multicodec prefix: {:?}",
+            magic_bytes
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_did_key;
+    use crate::{
+        ed25519::Ed25519KeyMaterial, k256::K256KeyMaterial, p256::P256KeyMaterial,
+        rsa::RsaKeyMaterial,
+    };
+    use ucan::crypto::KeyMaterial;
+
+    #[tokio::test]
+    async fn it_parses_an_ed25519_did_key() {
+        let key_material = Ed25519KeyMaterial::generate();
+        let did = key_material.get_did().await.unwrap();
+        let parsed = parse_did_key(did.as_str()).unwrap();
+        assert_eq!(parsed.get_did().await.unwrap(), did);
+    }
+
+    #[tokio::test]
+    async fn it_parses_a_p256_did_key() {
+        let key_material = P256KeyMaterial::generate();
+        let did = key_material.get_did().await.unwrap();
+        let parsed = parse_did_key(did.as_str()).unwrap();
+        assert_eq!(parsed.get_did().await.unwrap(), did);
+    }
+
+    #[tokio::test]
+    async fn it_parses_a_secp256k1_did_key() {
+        let key_material = K256KeyMaterial::generate();
+        let did = key_material.get_did().await.unwrap();
+        let parsed = parse_did_key(did.as_str()).unwrap();
+        assert_eq!(parsed.get_did().await.unwrap(), did);
+    }
+
+    #[tokio::test]
+    async fn it_parses_an_rsa_did_key() {
+        let key_material = RsaKeyMaterial::generate(2048).unwrap();
+        let did = key_material.get_did().await.unwrap();
+        let parsed = parse_did_key(did.as_str()).unwrap();
+        assert_eq!(parsed.get_did().await.unwrap(), did);
+    }
+
+    #[test]
+    fn it_rejects_an_unknown_codec() {
+        let did = format!("did:key:z{}", bs58::encode([0xff, 0xff, 0x00]).into_string());
+        assert!(parse_did_key(did.as_str()).is_err());
+    }
+
+    #[test]
+    fn it_rejects_a_non_did_key_string() {
+        assert!(parse_did_key("did:web:example.com").is_err());
+    }
+}
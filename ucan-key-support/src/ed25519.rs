@@ -17,6 +17,34 @@ pub fn bytes_to_ed25519_key(bytes: Vec<u8>) -> Result<Box<dyn KeyMaterial>> {
 #[derive(Clone)]
 pub struct Ed25519KeyMaterial(pub Ed25519PublicKey, pub Option<Ed25519PrivateKey>);
 
+impl Ed25519KeyMaterial {
+    /// Generate a new, random Ed25519 keypair.
+    pub fn generate() -> Self {
+        let rng = rand::thread_rng();
+        let private_key = Ed25519PrivateKey::new(rng);
+        let public_key = Ed25519PublicKey::from(&private_key);
+        Ed25519KeyMaterial(public_key, Some(private_key))
+    }
+
+    /// Rebuild a keypair from the raw 32-byte secret key, e.g. one persisted via [`Ed25519KeyMaterial::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let private_key = Ed25519PrivateKey::try_from(bytes)?;
+        let public_key = Ed25519PublicKey::from(&private_key);
+        Ok(Ed25519KeyMaterial(public_key, Some(private_key)))
+    }
+
+    /// Rebuild a keypair from a fixed-size 32-byte seed. For Ed25519 the seed
+    /// *is* the raw secret key, so this is equivalent to [`Ed25519KeyMaterial::from_bytes`].
+    pub fn from_seed(seed: &[u8; 32]) -> Result<Self> {
+        Self::from_bytes(seed.as_slice())
+    }
+
+    /// The raw secret key bytes, suitable for persisting and later reloading via [`Ed25519KeyMaterial::from_bytes`].
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        self.1.map(|private_key| private_key.as_ref().to_vec())
+    }
+}
+
 #[cfg_attr(all(target_arch="wasm32", feature = "web"), async_trait(?Send))]
 #[cfg_attr(any(not(target_arch = "wasm32"), not(feature = "web")), async_trait)]
 impl KeyMaterial for Ed25519KeyMaterial {
@@ -0,0 +1,118 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+
+use k256::ecdsa::{
+    signature::{Signer, Verifier},
+    Signature, SigningKey as K256PrivateKey, VerifyingKey as K256PublicKey,
+};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+use ucan::crypto::KeyMaterial;
+
+pub const SECP256K1_MAGIC_BYTES: [u8; 2] = [0xe7, 0x01];
+
+pub fn bytes_to_k256_key(bytes: Vec<u8>) -> Result<Box<dyn KeyMaterial>> {
+    let public_key = K256PublicKey::from_sec1_bytes(bytes.as_slice())?;
+    Ok(Box::new(K256KeyMaterial(public_key, None)))
+}
+
+#[derive(Clone)]
+pub struct K256KeyMaterial(pub K256PublicKey, pub Option<K256PrivateKey>);
+
+impl K256KeyMaterial {
+    /// Generate a new, random secp256k1 keypair.
+    pub fn generate() -> Self {
+        let rng = rand::thread_rng();
+        let private_key = K256PrivateKey::random(rng);
+        let public_key = K256PublicKey::from(&private_key);
+        K256KeyMaterial(public_key, Some(private_key))
+    }
+
+    /// Rebuild a keypair from the raw secret scalar bytes, e.g. one persisted via [`K256KeyMaterial::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let private_key = K256PrivateKey::from_slice(bytes)?;
+        let public_key = K256PublicKey::from(&private_key);
+        Ok(K256KeyMaterial(public_key, Some(private_key)))
+    }
+
+    /// Rebuild a keypair from a fixed-size 32-byte seed, i.e. the raw secret scalar.
+    /// Equivalent to [`K256KeyMaterial::from_bytes`], provided for parity with the
+    /// other key-material types' constructors.
+    pub fn from_seed(seed: &[u8; 32]) -> Result<Self> {
+        Self::from_bytes(seed.as_slice())
+    }
+
+    /// The raw secret key bytes, suitable for persisting and later reloading via [`K256KeyMaterial::from_bytes`].
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        self.1.as_ref().map(|private_key| private_key.to_bytes().to_vec())
+    }
+}
+
+#[cfg_attr(all(target_arch="wasm32", feature = "web"), async_trait(?Send))]
+#[cfg_attr(any(not(target_arch = "wasm32"), not(feature = "web")), async_trait)]
+impl KeyMaterial for K256KeyMaterial {
+    fn get_jwt_algorithm_name(&self) -> String {
+        "ES256K".into()
+    }
+
+    async fn get_did(&self) -> Result<String> {
+        let point = self.0.to_encoded_point(true);
+        let bytes = [SECP256K1_MAGIC_BYTES.as_slice(), point.as_bytes()].concat();
+        Ok(format!("did:key:z{}", bs58::encode(bytes).into_string()))
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        match &self.1 {
+            Some(private_key) => {
+                let signature: Signature = private_key.sign(payload);
+                let signature = signature.normalize_s().unwrap_or(signature);
+                Ok(signature.to_bytes().to_vec())
+            }
+            None => Err(anyhow!("No private key; cannot sign data")),
+        }
+    }
+
+    async fn verify(&self, payload: &[u8], signature: &[u8]) -> Result<()> {
+        let signature = Signature::try_from(signature)?;
+        let signature = signature.normalize_s().unwrap_or(signature);
+        self.0
+            .verify(payload, &signature)
+            .map_err(|error| anyhow!(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes_to_k256_key, K256KeyMaterial, SECP256K1_MAGIC_BYTES};
+    use k256::ecdsa::{SigningKey as K256PrivateKey, VerifyingKey as K256PublicKey};
+    use ucan::{
+        builder::UcanBuilder,
+        crypto::{did::DidParser, KeyMaterial},
+        ucan::Ucan,
+    };
+
+    #[tokio::test]
+    async fn it_can_sign_and_verify_a_ucan() {
+        let rng = rand::thread_rng();
+        let private_key = K256PrivateKey::random(rng);
+        let public_key = K256PublicKey::from(&private_key);
+
+        let key_material = K256KeyMaterial(public_key, Some(private_key));
+        let token_string = UcanBuilder::new()
+            .issued_by(&key_material)
+            .for_audience(key_material.get_did().await.unwrap().as_str())
+            .with_lifetime(60)
+            .build()
+            .unwrap()
+            .sign()
+            .await
+            .unwrap()
+            .encode()
+            .unwrap();
+
+        let did_parser = DidParser::new(&[(SECP256K1_MAGIC_BYTES, bytes_to_k256_key)]);
+
+        let ucan = Ucan::try_from_token_string(token_string.as_str()).unwrap();
+        ucan.check_signature(did_parser.clone()).await.unwrap();
+    }
+}
@@ -0,0 +1,5 @@
+pub mod did;
+pub mod ed25519;
+pub mod k256;
+pub mod p256;
+pub mod rsa;
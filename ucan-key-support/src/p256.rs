@@ -0,0 +1,116 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+
+use p256::ecdsa::{
+    signature::{Signer, Verifier},
+    Signature, SigningKey as P256PrivateKey, VerifyingKey as P256PublicKey,
+};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+use ucan::crypto::KeyMaterial;
+
+pub const P256_MAGIC_BYTES: [u8; 2] = [0x80, 0x24];
+
+pub fn bytes_to_p256_key(bytes: Vec<u8>) -> Result<Box<dyn KeyMaterial>> {
+    let public_key = P256PublicKey::from_sec1_bytes(bytes.as_slice())?;
+    Ok(Box::new(P256KeyMaterial(public_key, None)))
+}
+
+#[derive(Clone)]
+pub struct P256KeyMaterial(pub P256PublicKey, pub Option<P256PrivateKey>);
+
+impl P256KeyMaterial {
+    /// Generate a new, random P-256 keypair.
+    pub fn generate() -> Self {
+        let rng = rand::thread_rng();
+        let private_key = P256PrivateKey::random(rng);
+        let public_key = P256PublicKey::from(&private_key);
+        P256KeyMaterial(public_key, Some(private_key))
+    }
+
+    /// Rebuild a keypair from the raw secret scalar bytes, e.g. one persisted via [`P256KeyMaterial::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let private_key = P256PrivateKey::from_slice(bytes)?;
+        let public_key = P256PublicKey::from(&private_key);
+        Ok(P256KeyMaterial(public_key, Some(private_key)))
+    }
+
+    /// Rebuild a keypair from a fixed-size 32-byte seed, i.e. the raw secret scalar.
+    /// Equivalent to [`P256KeyMaterial::from_bytes`], provided for parity with the
+    /// other key-material types' constructors.
+    pub fn from_seed(seed: &[u8; 32]) -> Result<Self> {
+        Self::from_bytes(seed.as_slice())
+    }
+
+    /// The raw secret key bytes, suitable for persisting and later reloading via [`P256KeyMaterial::from_bytes`].
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        self.1.as_ref().map(|private_key| private_key.to_bytes().to_vec())
+    }
+}
+
+#[cfg_attr(all(target_arch="wasm32", feature = "web"), async_trait(?Send))]
+#[cfg_attr(any(not(target_arch = "wasm32"), not(feature = "web")), async_trait)]
+impl KeyMaterial for P256KeyMaterial {
+    fn get_jwt_algorithm_name(&self) -> String {
+        "ES256".into()
+    }
+
+    async fn get_did(&self) -> Result<String> {
+        let point = self.0.to_encoded_point(true);
+        let bytes = [P256_MAGIC_BYTES.as_slice(), point.as_bytes()].concat();
+        Ok(format!("did:key:z{}", bs58::encode(bytes).into_string()))
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        match &self.1 {
+            Some(private_key) => {
+                let signature: Signature = private_key.sign(payload);
+                Ok(signature.to_bytes().to_vec())
+            }
+            None => Err(anyhow!("No private key; cannot sign data")),
+        }
+    }
+
+    async fn verify(&self, payload: &[u8], signature: &[u8]) -> Result<()> {
+        let signature = Signature::try_from(signature)?;
+        self.0
+            .verify(payload, &signature)
+            .map_err(|error| anyhow!(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes_to_p256_key, P256KeyMaterial, P256_MAGIC_BYTES};
+    use p256::ecdsa::{SigningKey as P256PrivateKey, VerifyingKey as P256PublicKey};
+    use ucan::{
+        builder::UcanBuilder,
+        crypto::{did::DidParser, KeyMaterial},
+        ucan::Ucan,
+    };
+
+    #[tokio::test]
+    async fn it_can_sign_and_verify_a_ucan() {
+        let rng = rand::thread_rng();
+        let private_key = P256PrivateKey::random(rng);
+        let public_key = P256PublicKey::from(&private_key);
+
+        let key_material = P256KeyMaterial(public_key, Some(private_key));
+        let token_string = UcanBuilder::new()
+            .issued_by(&key_material)
+            .for_audience(key_material.get_did().await.unwrap().as_str())
+            .with_lifetime(60)
+            .build()
+            .unwrap()
+            .sign()
+            .await
+            .unwrap()
+            .encode()
+            .unwrap();
+
+        let did_parser = DidParser::new(&[(P256_MAGIC_BYTES, bytes_to_p256_key)]);
+
+        let ucan = Ucan::try_from_token_string(token_string.as_str()).unwrap();
+        ucan.check_signature(did_parser.clone()).await.unwrap();
+    }
+}
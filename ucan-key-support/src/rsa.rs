@@ -0,0 +1,141 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey, EncodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::{Pkcs1v15Sign, RsaPrivateKey, RsaPublicKey};
+use sha2::{Digest, Sha256};
+
+use ucan::crypto::KeyMaterial;
+
+pub const RSA_MAGIC_BYTES: [u8; 2] = [0x85, 0x24];
+
+pub fn bytes_to_rsa_key(bytes: Vec<u8>) -> Result<Box<dyn KeyMaterial>> {
+    let public_key = RsaPublicKey::from_pkcs1_der(bytes.as_slice())?;
+    Ok(Box::new(RsaKeyMaterial(public_key, None)))
+}
+
+#[derive(Clone)]
+pub struct RsaKeyMaterial(pub RsaPublicKey, pub Option<RsaPrivateKey>);
+
+impl RsaKeyMaterial {
+    /// Generate a new RSA keypair of the given modulus size, in bits.
+    pub fn generate(bits: usize) -> Result<Self> {
+        let mut rng = rand::thread_rng();
+        let private_key = RsaPrivateKey::new(&mut rng, bits)?;
+        let public_key = RsaPublicKey::from(&private_key);
+        Ok(RsaKeyMaterial(public_key, Some(private_key)))
+    }
+
+    /// Import a public key from a PKCS#1 or PKCS#8 PEM document.
+    pub fn from_public_key_pem(pem: &str) -> Result<Self> {
+        let public_key = RsaPublicKey::from_public_key_pem(pem)
+            .map_err(|error| anyhow!(error))
+            .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem).map_err(|error| anyhow!(error)))?;
+        Ok(RsaKeyMaterial(public_key, None))
+    }
+
+    /// Import a public key from a PKCS#1 or PKCS#8 DER document.
+    pub fn from_public_key_der(der: &[u8]) -> Result<Self> {
+        let public_key = RsaPublicKey::from_public_key_der(der)
+            .map_err(|error| anyhow!(error))
+            .or_else(|_| RsaPublicKey::from_pkcs1_der(der).map_err(|error| anyhow!(error)))?;
+        Ok(RsaKeyMaterial(public_key, None))
+    }
+
+    /// Import a private key from a PKCS#1 or PKCS#8 PEM document.
+    pub fn from_private_key_pem(pem: &str) -> Result<Self> {
+        let private_key = RsaPrivateKey::from_pkcs8_pem(pem)
+            .map_err(|error| anyhow!(error))
+            .or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem).map_err(|error| anyhow!(error)))?;
+        let public_key = RsaPublicKey::from(&private_key);
+        Ok(RsaKeyMaterial(public_key, Some(private_key)))
+    }
+
+    /// Import a private key from a PKCS#1 or PKCS#8 DER document.
+    pub fn from_private_key_der(der: &[u8]) -> Result<Self> {
+        let private_key = RsaPrivateKey::from_pkcs8_der(der)
+            .map_err(|error| anyhow!(error))
+            .or_else(|_| RsaPrivateKey::from_pkcs1_der(der).map_err(|error| anyhow!(error)))?;
+        let public_key = RsaPublicKey::from(&private_key);
+        Ok(RsaKeyMaterial(public_key, Some(private_key)))
+    }
+}
+
+#[cfg_attr(all(target_arch="wasm32", feature = "web"), async_trait(?Send))]
+#[cfg_attr(any(not(target_arch = "wasm32"), not(feature = "web")), async_trait)]
+impl KeyMaterial for RsaKeyMaterial {
+    fn get_jwt_algorithm_name(&self) -> String {
+        "RS256".into()
+    }
+
+    async fn get_did(&self) -> Result<String> {
+        let der = self.0.to_pkcs1_der()?;
+        let bytes = [RSA_MAGIC_BYTES.as_slice(), der.as_bytes()].concat();
+        Ok(format!("did:key:z{}", bs58::encode(bytes).into_string()))
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        match &self.1 {
+            Some(private_key) => {
+                let hashed = Sha256::digest(payload);
+                let signature = private_key.sign(Pkcs1v15Sign::new::<Sha256>(), &hashed)?;
+                Ok(signature)
+            }
+            None => Err(anyhow!("No private key; cannot sign data")),
+        }
+    }
+
+    async fn verify(&self, payload: &[u8], signature: &[u8]) -> Result<()> {
+        let hashed = Sha256::digest(payload);
+        self.0
+            .verify(Pkcs1v15Sign::new::<Sha256>(), &hashed, signature)
+            .map_err(|error| anyhow!(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes_to_rsa_key, RsaKeyMaterial, RSA_MAGIC_BYTES};
+    use rsa::pkcs1::DecodeRsaPublicKey;
+    use rsa::RsaPrivateKey;
+    use ucan::{
+        builder::UcanBuilder,
+        crypto::{did::DidParser, KeyMaterial},
+        ucan::Ucan,
+    };
+
+    #[tokio::test]
+    async fn it_can_sign_and_verify_a_ucan() {
+        let key_material = RsaKeyMaterial::generate(2048).unwrap();
+        let token_string = UcanBuilder::new()
+            .issued_by(&key_material)
+            .for_audience(key_material.get_did().await.unwrap().as_str())
+            .with_lifetime(60)
+            .build()
+            .unwrap()
+            .sign()
+            .await
+            .unwrap()
+            .encode()
+            .unwrap();
+
+        let did_parser = DidParser::new(&[(RSA_MAGIC_BYTES, bytes_to_rsa_key)]);
+
+        let ucan = Ucan::try_from_token_string(token_string.as_str()).unwrap();
+        ucan.check_signature(did_parser.clone()).await.unwrap();
+    }
+
+    #[test]
+    fn it_imports_a_pkcs1_der_public_key() {
+        let mut rng = rand::thread_rng();
+        let private_key = RsaPrivateKey::new(&mut rng, 2048).unwrap();
+        let public_key = rsa::RsaPublicKey::from(&private_key);
+        let der = rsa::pkcs1::EncodeRsaPublicKey::to_pkcs1_der(&public_key).unwrap();
+
+        let parsed = bytes_to_rsa_key(der.as_bytes().to_vec()).unwrap();
+        let reparsed_der = rsa::RsaPublicKey::from_pkcs1_der(der.as_bytes()).unwrap();
+        assert_eq!(parsed.get_jwt_algorithm_name(), "RS256");
+        assert_eq!(reparsed_der, public_key);
+    }
+}